@@ -0,0 +1,133 @@
+//! Canonical prefix-code (Huffman) encoding/decoding built directly on
+//! `BitReader`/`BitWriter`: encoding uses the packed `write_bits` primitive,
+//! decoding reads one bit at a time.
+
+use crate::{BitError, BitReader, BitWriter};
+use std::collections::HashMap;
+use std::io::{BufRead, Write};
+
+/// A canonical Huffman code table built from per-symbol code lengths
+#[derive(Debug, Clone)]
+pub struct Huffman {
+    /// symbol -> (code, code length in bits)
+    encode: HashMap<usize, (u64, u8)>,
+    /// longest code length present in the table
+    max_len: u8,
+    /// number of symbols assigned a code of each length, indexed by length
+    count: Vec<u32>,
+    /// first code of each length, indexed by length
+    first_code: Vec<u64>,
+    /// last code of each length, indexed by length
+    last_code: Vec<u64>,
+    /// index into `symbols` of the first symbol with a given length, indexed by length
+    symbol_offset: Vec<usize>,
+    /// symbols ordered by (length, code)
+    symbols: Vec<usize>,
+}
+
+impl Huffman {
+    /// Builds a canonical Huffman code table from per-symbol code lengths
+    ///
+    /// `lengths[symbol]` is the code length in bits for that symbol, or `0` if the
+    /// symbol is unused. Codes are assigned the standard way: count how many codes
+    /// of each length exist, derive the first code per length via
+    /// `code = (code + count[len - 1]) << 1`, then hand out consecutive codes to
+    /// symbols in symbol order.
+    pub fn from_lengths(lengths: &[u8]) -> Result<Huffman, BitError> {
+        let max_len = *lengths.iter().max().unwrap_or(&0);
+        if max_len == 0 || max_len as usize > 64 {
+            return Err(BitError::InvalidLength(max_len as usize));
+        }
+        let max_len = max_len as usize;
+
+        let mut count = vec![0u32; max_len + 1];
+        for &len in lengths {
+            if len > 0 {
+                count[len as usize] += 1;
+            }
+        }
+
+        let mut next_code = vec![0u64; max_len + 1];
+        let mut code = 0u64;
+        for len in 1..=max_len {
+            code = (code + count[len - 1] as u64) << 1;
+            next_code[len] = code;
+        }
+
+        let mut first_code = vec![0u64; max_len + 1];
+        let mut last_code = vec![0u64; max_len + 1];
+        let mut symbol_offset = vec![0usize; max_len + 1];
+        let mut symbols = Vec::new();
+        let mut encode = HashMap::new();
+
+        for len in 1..=max_len {
+            first_code[len] = next_code[len];
+            symbol_offset[len] = symbols.len();
+
+            for (symbol, &symbol_len) in lengths.iter().enumerate() {
+                if symbol_len as usize == len {
+                    encode.insert(symbol, (next_code[len], len as u8));
+                    symbols.push(symbol);
+                    next_code[len] += 1;
+                }
+            }
+
+            if count[len] > 0 {
+                last_code[len] = next_code[len] - 1;
+            }
+        }
+
+        Ok(Huffman { encode, max_len: max_len as u8, count, first_code, last_code, symbol_offset, symbols })
+    }
+
+    /// Writes the canonical code for `symbol` to `writer`
+    pub fn encode_symbol<W: Write>(&self, writer: &mut BitWriter<W>, symbol: usize) -> Result<(), BitError> {
+        let &(code, len) = self.encode.get(&symbol).ok_or(BitError::UnknownSymbol(symbol))?;
+        writer.write_bits(code, len as usize)
+    }
+
+    /// Reads one bit at a time from `reader`, accumulating a code and comparing it
+    /// against the first/last code of the current length, until a symbol is found
+    ///
+    /// Returns the decoded symbol and the number of bits consumed.
+    pub fn decode_symbol<R: BufRead>(&self, reader: &mut BitReader<R>) -> Result<(usize, u8), BitError> {
+        let mut code: u64 = 0;
+
+        for len in 1..=self.max_len as usize {
+            code = (code << 1) | (reader.read()? as u64);
+
+            if self.count[len] > 0 && code >= self.first_code[len] && code <= self.last_code[len] {
+                let index = self.symbol_offset[len] + (code - self.first_code[len]) as usize;
+                return Ok((self.symbols[index], len as u8));
+            }
+        }
+
+        Err(BitError::InvalidCode)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BitWriter;
+    use std::io::Cursor;
+
+    #[test]
+    fn encode_decode_round_trip() {
+        let lengths = [2u8, 1, 3, 3];
+        let huffman = Huffman::from_lengths(&lengths).unwrap();
+
+        let symbols = [1, 0, 2, 3, 1, 0];
+        let mut writer = BitWriter::new(Vec::new(), true);
+        for &symbol in &symbols {
+            huffman.encode_symbol(&mut writer, symbol).unwrap();
+        }
+        let bytes = writer.into_inner().unwrap();
+
+        let mut reader = BitReader::new(Cursor::new(bytes)).unwrap();
+        for &expected in &symbols {
+            let (symbol, _len) = huffman.decode_symbol(&mut reader).unwrap();
+            assert_eq!(symbol, expected);
+        }
+    }
+}
@@ -2,9 +2,10 @@
 //!
 //! A simple wrapper around the `BufRead` and `Write` Trait for bitwise IO
 //!
-use std::io::{BufRead, Write, ErrorKind};
+use std::io::{BufRead, Write, ErrorKind, Seek, SeekFrom};
 use std::fmt::{Display, Formatter};
-use std::collections::VecDeque;
+
+pub mod huffman;
 
 
 /// Bit representation
@@ -14,6 +15,37 @@ pub enum Bit {
     One = 1,
 }
 
+/// Errors produced by the bitwise read/write operations
+#[derive(Debug)]
+pub enum BitError {
+    /// An I/O error from the underlying reader/writer
+    Io(std::io::Error),
+    /// The stream was exhausted before the requested bits could be read
+    EndOfStream,
+    /// A requested bit length was outside the supported `1..=64` range
+    InvalidLength(usize),
+    /// `BitReader::peek` was asked for more bits than it can serve from its buffered window without refilling it
+    PeekWindowExceeded(usize),
+    /// No canonical code of any length matched while decoding a Huffman symbol
+    InvalidCode,
+    /// `huffman::Huffman::encode_symbol` was asked to encode a symbol with no assigned code
+    UnknownSymbol(usize),
+}
+
+/// Selects how bits are packed within a byte, and how bytes are grouped into words
+///
+/// `BigEndian` is the crate's original, MSB-first behavior (e.g. JPEG-style bitstreams).
+/// The `LittleEndian*` variants pack LSB-first, as used by DEFLATE-style bitstreams;
+/// the `16`/`32` variants additionally gather bytes into little-endian words before
+/// bits are drawn from them, for formats that store multi-bit fields word-at-a-time.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum BitOrder {
+    BigEndian,
+    LittleEndian,
+    LittleEndian16,
+    LittleEndian32,
+}
+
 /// Reader for bitwise reading from `BufRead`
 #[derive(Debug)]
 pub struct BitReader<R: BufRead> {
@@ -21,6 +53,14 @@ pub struct BitReader<R: BufRead> {
     buf: Box<[u8]>,
     pos: usize,
     init_read: bool,
+    order: BitOrder,
+    /// Fill/drain cache used by the `LittleEndian16`/`LittleEndian32` word modes
+    cache: u64,
+    /// Number of valid bits currently held in `cache`
+    cache_bits: u8,
+    /// Current bit position: bits consumed since the reader was created, or,
+    /// after `seek_bits`, the bit offset seeked to plus the bits consumed since
+    total_bits: u64,
 }
 
 const DEFAULT_BUF_SIZE: usize = 1024;
@@ -28,31 +68,60 @@ const DEFAULT_BUF_SIZE: usize = 1024;
 /// Writer to bitwise writing to `Write`
 #[derive(Debug)]
 pub struct BitWriter<W: Write> {
-    inner: W,
-    buf: VecDeque<Bit>,
+    /// `None` only after `into_inner` has taken it out, just before `self` is dropped
+    inner: Option<W>,
+    /// Shift accumulator holding the bits not yet forming a complete byte/word
+    acc: u64,
+    /// Number of valid bits currently held in `acc`
+    nbits: u8,
+    /// Completed bytes awaiting a flush to `inner`
+    bytes: Vec<u8>,
+    /// Number of completed bytes to buffer before flushing to `inner`
+    capacity: usize,
     pub pad_zero: bool,
+    order: BitOrder,
 }
 
 /**************************************************************************************************
                         BitReader - Implementations
  *************************************************************************************************/
 impl<R: BufRead> BitReader<R> {
-    /// Creates a new BitReader from a BufRead
+    /// Creates a new BitReader from a BufRead, reading in `BitOrder::BigEndian` order
     /// Buffer is not filled on create
-    pub fn new(mut inner: R) -> std::io::Result<BitReader<R>> {
+    pub fn new(inner: R) -> Result<BitReader<R>, BitError> {
+        BitReader::with_order(inner, BitOrder::BigEndian)
+    }
+
+    /// Creates a new BitReader from a BufRead using the given `BitOrder`
+    /// Buffer is filled on create, without consuming it from the inner reader
+    pub fn with_order(mut inner: R, order: BitOrder) -> Result<BitReader<R>, BitError> {
         let buf = inner.fill_buf()?.to_vec().into_boxed_slice();
 
-        Ok(BitReader { inner, buf, pos: 0 , init_read: false})
+        Ok(BitReader { inner, buf, pos: 0, init_read: false, order, cache: 0, cache_bits: 0, total_bits: 0 })
     }
 
-    /// Read a single Bit from BufRead
-    pub fn read(&mut self) -> std::io::Result<Bit> {
-        if self.init_read == false {
+    /// Read a single Bit from BufRead, in the reader's configured `BitOrder`
+    pub fn read(&mut self) -> Result<Bit, BitError> {
+        let bit = match self.order {
+            BitOrder::BigEndian => self.read_be(),
+            BitOrder::LittleEndian => self.read_le(),
+            BitOrder::LittleEndian16 => self.read_le_word(2),
+            BitOrder::LittleEndian32 => self.read_le_word(4),
+        }?;
+
+        self.total_bits += 1;
+
+        Ok(bit)
+    }
+
+    /// Read a single Bit, MSB-first within each byte
+    fn read_be(&mut self) -> Result<Bit, BitError> {
+        if !self.init_read {
             reader_fill_buf(self)?;
         }
 
         if self.is_empty() {
-            Err(std::io::Error::new(ErrorKind::Other, "End of File"))
+            return Err(BitError::EndOfStream);
         }
 
         let mut byte_offset = self.pos / 8;
@@ -77,8 +146,59 @@ impl<R: BufRead> BitReader<R> {
         Ok(bit)
     }
 
+    /// Read a single Bit, LSB-first within each byte
+    fn read_le(&mut self) -> Result<Bit, BitError> {
+        if !self.init_read {
+            reader_fill_buf(self)?;
+        }
+
+        if self.is_empty() {
+            return Err(BitError::EndOfStream);
+        }
+
+        let mut byte_offset = self.pos / 8;
+        let mut bit_offset = self.pos % 8;
+
+        let byte = self.buf[byte_offset];
+
+        let mask = 1 << bit_offset;
+
+        let bit = Bit::from(byte & mask);
+
+        bit_offset += 1;
+        if bit_offset > 7 {
+            let byte_o = reader_update(self, byte_offset + 1)?;
+
+            byte_offset = byte_o;
+            bit_offset = 0;
+        }
+
+        self.pos = byte_offset * 8 + bit_offset;
+
+        Ok(bit)
+    }
+
+    /// Read a single Bit out of the `word_bytes`-wide little-endian word cache,
+    /// refilling the cache from the underlying buffer when it runs dry
+    fn read_le_word(&mut self, word_bytes: usize) -> Result<Bit, BitError> {
+        if self.cache_bits == 0 {
+            let mut word: u64 = 0;
+            for i in 0..word_bytes {
+                word |= (reader_next_byte(self)? as u64) << (8 * i);
+            }
+            self.cache = word;
+            self.cache_bits = (word_bytes * 8) as u8;
+        }
+
+        let bit = Bit::from(self.cache & 1);
+        self.cache >>= 1;
+        self.cache_bits -= 1;
+
+        Ok(bit)
+    }
+
     /// Try Reading n Bits from BufRead
-    pub fn read_multi(&mut self, n: usize) -> std::io::Result<Vec<Bit>> {
+    pub fn read_multi(&mut self, n: usize) -> Result<Vec<Bit>, BitError> {
         let mut output = Vec::with_capacity(n);
 
         for _ in 0..n {
@@ -88,6 +208,66 @@ impl<R: BufRead> BitReader<R> {
         Ok(output)
     }
 
+    /// Reads `n` (1..=64) Bits and assembles them MSB-first into a `u64`
+    ///
+    /// This is the packed counterpart to `read_multi`, avoiding the
+    /// one-`Bit`-per-byte allocation for fields such as a length prefix.
+    pub fn read_uint(&mut self, n: usize) -> Result<u64, BitError> {
+        if n == 0 || n > 64 {
+            return Err(BitError::InvalidLength(n));
+        }
+
+        let mut acc: u64 = 0;
+        for _ in 0..n {
+            acc = (acc << 1) | (self.read()? as u64);
+        }
+
+        Ok(acc)
+    }
+
+    /// Reads `n` (1..=64) bits MSB-first without advancing the reader's position
+    ///
+    /// Lets a decoder branch on upcoming bits (e.g. a prefix code) and then
+    /// consume the chosen width. Restricted to bits already sitting in the
+    /// currently filled buffer (plus any word-mode cache): serving a peek that
+    /// crosses into the next buffer chunk would advance the underlying reader,
+    /// which a non-consuming `peek` can't undo, so that case is rejected with
+    /// `BitError::PeekWindowExceeded` instead of silently corrupting later reads.
+    /// Consuming the very last bit of the buffered window triggers the same
+    /// eager refill as crossing past it, so the guard must be strict: `n` may
+    /// reach at most one bit short of the buffered window, not all of it. In
+    /// the word modes the cache refills a whole word at a time, so a peek may
+    /// not pull the word that holds the window's last byte.
+    pub fn peek(&mut self, n: usize) -> Result<u64, BitError> {
+        if n == 0 || n > 64 {
+            return Err(BitError::InvalidLength(n));
+        }
+
+        let window = (self.buf.len() as u64 * 8).saturating_sub(self.pos as u64);
+        let buffered = match self.order {
+            BitOrder::BigEndian | BitOrder::LittleEndian => window.saturating_sub(1),
+            BitOrder::LittleEndian16 => window.saturating_sub(8) / 16 * 16,
+            BitOrder::LittleEndian32 => window.saturating_sub(8) / 32 * 32,
+        };
+        if n as u64 > buffered + self.cache_bits as u64 {
+            return Err(BitError::PeekWindowExceeded(n));
+        }
+
+        let pos = self.pos;
+        let cache = self.cache;
+        let cache_bits = self.cache_bits;
+        let total_bits = self.total_bits;
+
+        let value = self.read_uint(n);
+
+        self.pos = pos;
+        self.cache = cache;
+        self.cache_bits = cache_bits;
+        self.total_bits = total_bits;
+
+        value
+    }
+
     /// Returns true if the Buffer is empty
     /// Always true after newly created
     pub fn is_empty(&self) -> bool {
@@ -98,10 +278,97 @@ impl<R: BufRead> BitReader<R> {
     pub fn buf_len(&self) -> usize {
         self.buf.len()
     }
+
+    /// Returns the current bit position: the number of bits consumed since the
+    /// reader was created, or, after `seek_bits`, the bit offset seeked to plus
+    /// the bits consumed since
+    pub fn bit_pos(&self) -> u64 {
+        self.total_bits
+    }
+
+    /// Returns true if the current position is on a byte boundary
+    pub fn byte_aligned(&self) -> bool {
+        self.total_bits.is_multiple_of(8)
+    }
+
+    /// Reads (and discards) bits up to the next byte boundary
+    pub fn align(&mut self) -> Result<(), BitError> {
+        while !self.byte_aligned() {
+            self.read()?;
+        }
+        Ok(())
+    }
+
+    /// Borrows the wrapped `BufRead`
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Mutably borrows the wrapped `BufRead`
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// Consumes the `BitReader`, returning the wrapped `BufRead`
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+/// Seeking support, only available when the underlying reader also implements `Seek`
+impl<R: BufRead + Seek> BitReader<R> {
+    /// Repositions the reader to the given absolute bit offset
+    ///
+    /// Translates the target bit offset into a byte seek on the underlying `Seek`,
+    /// then discards the remainder bits within the landing byte.
+    pub fn seek_bits(&mut self, from: SeekFrom) -> Result<u64, BitError> {
+        let target = self.resolve_seek_target(from)?;
+
+        let byte_offset = target / 8;
+        let bit_remainder = (target % 8) as usize;
+
+        self.inner.seek(SeekFrom::Start(byte_offset))?;
+
+        // The cached `buf` reflects bytes read before the seek and is no longer
+        // valid at the new stream position; refill it directly rather than via
+        // `reader_fill_buf`, which would `consume` the stale buffer from a
+        // reader that has already moved past it.
+        self.buf = self.inner.fill_buf()?.to_vec().into_boxed_slice();
+        self.init_read = true;
+
+        self.pos = 0;
+        self.cache = 0;
+        self.cache_bits = 0;
+        self.total_bits = byte_offset * 8;
+
+        for _ in 0..bit_remainder {
+            self.read()?;
+        }
+
+        Ok(target)
+    }
+
+    /// Resolves a `SeekFrom` into an absolute target bit offset
+    fn resolve_seek_target(&mut self, from: SeekFrom) -> Result<u64, BitError> {
+        let target = match from {
+            SeekFrom::Start(n) => n as i64,
+            SeekFrom::Current(delta) => self.total_bits as i64 + delta,
+            SeekFrom::End(delta) => {
+                let end_bytes = self.inner.seek(SeekFrom::End(0))?;
+                (end_bytes * 8) as i64 + delta
+            }
+        };
+
+        if target < 0 {
+            return Err(BitError::Io(std::io::Error::new(ErrorKind::InvalidInput, "seek to a negative bit offset")));
+        }
+
+        Ok(target as u64)
+    }
 }
 
 /// Consume the Buffer and read from file if byte_offset is buffer_length
-fn reader_update<R: BufRead>(reader: &mut BitReader<R>, byte_offset: usize) -> std::io::Result<usize> {
+fn reader_update<R: BufRead>(reader: &mut BitReader<R>, byte_offset: usize) -> Result<usize, BitError> {
     let buf_len = reader.buf.len();
 
     if byte_offset >= buf_len {
@@ -116,126 +383,253 @@ fn reader_update<R: BufRead>(reader: &mut BitReader<R>, byte_offset: usize) -> s
 }
 
 /// Consume buf.len() and fill buf
-fn reader_fill_buf<R: BufRead>(reader: &mut BitReader<R>) -> std::io::Result<()> {
-    reader.inner.consume(reader.buf.len());
+fn reader_fill_buf<R: BufRead>(reader: &mut BitReader<R>) -> Result<(), BitError> {
+    // Skip the consume+refill on the very first call: `new`/`with_order` already
+    // populated `buf` without consuming it, so doing this unconditionally would
+    // discard that initial data before a single bit of it was ever read.
+    if reader.init_read {
+        reader.inner.consume(reader.buf.len());
+
+        let buf = reader.inner.fill_buf()?;
 
-    let buf = reader.inner.fill_buf()?;
+        reader.buf = buf.to_vec().into_boxed_slice();
+    }
 
-    reader.buf = buf.to_vec().into_boxed_slice();
+    reader.init_read = true;
 
     Ok(())
 }
 
+/// Pulls the next whole byte from the buffer, refilling it as needed
+///
+/// Used by the word-grouping `BitOrder` modes to fill their bit cache.
+fn reader_next_byte<R: BufRead>(reader: &mut BitReader<R>) -> Result<u8, BitError> {
+    if !reader.init_read {
+        reader_fill_buf(reader)?;
+    }
+
+    if reader.is_empty() {
+        return Err(BitError::EndOfStream);
+    }
+
+    let byte_offset = reader.pos / 8;
+    let byte = reader.buf[byte_offset];
+
+    let next_offset = reader_update(reader, byte_offset + 1)?;
+    reader.pos = next_offset * 8;
+
+    Ok(byte)
+}
+
 
 /**************************************************************************************************
                         BitReader - Implementations
  *************************************************************************************************/
 impl<W: Write> BitWriter<W> {
-    /// Create a new BitWriter from a Write Trait with default capacity of 1024 Bytes
+    /// Create a new BitWriter from a Write Trait with default capacity of 1024 Bytes,
+    /// writing in `BitOrder::BigEndian` order
     pub fn new(inner: W, pad_zero: bool) -> Self {
-        BitWriter::with_capacity(DEFAULT_BUF_SIZE, inner, pad_zero)
+        BitWriter::with_order(inner, pad_zero, BitOrder::BigEndian)
+    }
+
+    /// Create a new BitWriter from a Write Trait with default capacity of 1024 Bytes,
+    /// using the given `BitOrder`
+    pub fn with_order(inner: W, pad_zero: bool, order: BitOrder) -> Self {
+        BitWriter::with_capacity(DEFAULT_BUF_SIZE, inner, pad_zero, order)
     }
 
-    /// Create a new BitWriter with a capacity (in Bytes)
-    pub fn with_capacity(capacity: usize, inner: W, pad_zero: bool) -> Self {
+    /// Create a new BitWriter with a capacity (the number of complete bytes to
+    /// buffer before flushing to the inner `Write`)
+    pub fn with_capacity(capacity: usize, inner: W, pad_zero: bool, order: BitOrder) -> Self {
         BitWriter {
-            inner,
-            buf: VecDeque::with_capacity(capacity * 8),
+            inner: Some(inner),
+            acc: 0,
+            nbits: 0,
+            bytes: Vec::with_capacity(capacity),
+            capacity,
             pad_zero,
+            order,
         }
     }
 
-    /// Writes a single Bit into the internal Buffer
-    /// If internal buffer is full -> Call internal write
-    pub fn write(&mut self, bit: Bit) -> std::io::Result<()> {
-        if self.buf.len() == DEFAULT_BUF_SIZE {
-            match self.write_buf() {
-                Ok(_) => {
-                    self.buf.push_back(bit);
-                    Ok(())
-                }
-                Err(err) => Err(err)
+    /// Writes a single Bit into the internal shift accumulator
+    /// Once the accumulator fills a byte/word it moves to the pending byte buffer;
+    /// once that buffer reaches capacity it is flushed to the inner `Write`
+    pub fn write(&mut self, bit: Bit) -> Result<(), BitError> {
+        match self.order {
+            BitOrder::BigEndian => self.acc = (self.acc << 1) | (bit as u64),
+            BitOrder::LittleEndian | BitOrder::LittleEndian16 | BitOrder::LittleEndian32 => {
+                self.acc |= (bit as u64) << self.nbits
             }
-        } else {
-            self.buf.push_back(bit);
-            Ok(())
         }
+        self.nbits += 1;
+
+        if self.nbits == writer_word_bits(self.order) {
+            writer_push_word(self);
+        }
+
+        if self.bytes.len() >= self.capacity {
+            self.flush_bytes()?;
+        }
+
+        Ok(())
     }
 
     /// Writes a vector of Bits into the internal Buffer
     /// If internal buffer is full -> Call internal write
-    pub fn write_bits(&mut self, bits: &Vec<Bit>) -> std::io::Result<()> {
+    pub fn write_multi(&mut self, bits: &Vec<Bit>) -> Result<(), BitError> {
         for bit in bits {
-            self.write(bit.clone())?
+            self.write(*bit)?
+        }
+        Ok(())
+    }
+
+    /// Writes the low `n` (1..=64) bits of `value` into the internal Buffer, MSB-first
+    ///
+    /// This is the packed counterpart to `write_multi`, letting callers push an
+    /// integer field without first materializing a `Vec<Bit>`.
+    pub fn write_bits(&mut self, value: u64, n: usize) -> Result<(), BitError> {
+        if n == 0 || n > 64 {
+            return Err(BitError::InvalidLength(n));
+        }
+
+        for i in 0..n {
+            self.write(Bit::from((value >> (n - 1 - i)) & 1))?;
         }
         Ok(())
     }
 
-    /// Write the internal Buffer and Pad with Zero? If needed
-    pub fn write_buf(&mut self) -> std::io::Result<()> {
-        writer_pad_buf(self);
+    /// Pads any trailing partial byte/word with `pad_zero`, then writes the pending
+    /// byte buffer to the inner `Write`
+    pub fn write_buf(&mut self) -> Result<(), BitError> {
+        let word_bits = writer_word_bits(self.order);
 
-        let bytes = writer_buf_to_bytes(self);
-        match self.inner.write(&*bytes) {
-            Ok(_) => {
-                self.inner.flush()
+        if self.nbits > 0 {
+            let pad_bit: u64 = if self.pad_zero { 0 } else { 1 };
+
+            while self.nbits < word_bits {
+                match self.order {
+                    BitOrder::BigEndian => self.acc = (self.acc << 1) | pad_bit,
+                    BitOrder::LittleEndian | BitOrder::LittleEndian16 | BitOrder::LittleEndian32 => {
+                        self.acc |= pad_bit << self.nbits
+                    }
+                }
+                self.nbits += 1;
             }
-            Err(err) => Err(err)
+
+            writer_push_word(self);
         }
+
+        self.flush_bytes()
     }
 
-    /// Removes excess bits that do not form a byte
+    /// Removes excess bits that do not form a byte/word, without padding them out
     pub fn discard_non_byte(&mut self) {
-        while self.buf.len() % 8 != 0 {
-            let _ = self.buf.pop_back();
-        }
+        self.acc = 0;
+        self.nbits = 0;
     }
 
-    /// Returns true if the Buffer is empty
+    /// Returns true if there are no pending bits or bytes left to write
     pub fn is_empty(&self) -> bool {
-        self.buf.is_empty()
+        self.nbits == 0 && self.bytes.is_empty()
     }
 
-    /// Returns the length of the internal buffer
+    /// Returns the length of the internal buffer, in bits
     pub fn buf_len(&self) -> usize {
-        self.buf.len()
+        self.bytes.len() * 8 + self.nbits as usize
+    }
+
+    /// Writes the pending, already-complete byte buffer to the inner `Write`
+    fn flush_bytes(&mut self) -> Result<(), BitError> {
+        if self.bytes.is_empty() {
+            return Ok(());
+        }
+
+        let inner = self.inner.as_mut().expect("BitWriter used after into_inner");
+        inner.write_all(&self.bytes)?;
+        inner.flush()?;
+        self.bytes.clear();
+
+        Ok(())
+    }
+
+    /// Borrows the wrapped `Write`
+    pub fn get_ref(&self) -> &W {
+        self.inner.as_ref().expect("BitWriter used after into_inner")
+    }
+
+    /// Mutably borrows the wrapped `Write`
+    pub fn get_mut(&mut self) -> &mut W {
+        self.inner.as_mut().expect("BitWriter used after into_inner")
+    }
+
+    /// Flushes any buffered bits (padding per `pad_zero`) and returns the wrapped `Write`
+    pub fn into_inner(mut self) -> Result<W, BitError> {
+        self.write_buf()?;
+
+        Ok(self.inner.take().expect("BitWriter used after into_inner"))
     }
 }
 
 impl<W: Write> Drop for BitWriter<W> {
     fn drop(&mut self) {
-        let _ = self.write_buf();
+        if self.inner.is_some() {
+            let _ = self.write_buf();
+        }
     }
 }
 
-/// Removes all complete bytes from the Buffer and returns them in a Vector
-fn writer_buf_to_bytes<W: Write>(writer: &mut BitWriter<W>) -> Vec<u8> {
-    let mut bytes = Vec::new();
+/// The number of bits the accumulator holds before it forms a complete byte/word,
+/// for the given `BitOrder`
+fn writer_word_bits(order: BitOrder) -> u8 {
+    match order {
+        BitOrder::BigEndian | BitOrder::LittleEndian => 8,
+        BitOrder::LittleEndian16 => 16,
+        BitOrder::LittleEndian32 => 32,
+    }
+}
 
-    while writer.buf.len() >= 8 {
-        let mut byte = 0;
-        for i in 0..8 {
-            byte |= writer.buf.pop_front().unwrap() as u8;
+/// Moves a full accumulator into the pending byte buffer as little-endian bytes
+/// (a single byte for the `BigEndian`/`LittleEndian` modes), then resets it
+fn writer_push_word<W: Write>(writer: &mut BitWriter<W>) {
+    let word_bytes = (writer_word_bits(writer.order) / 8) as usize;
 
-            if i < 7 {
-                byte = byte << 1;
-            }
-        }
-        bytes.push(byte);
+    for b in 0..word_bytes {
+        writer.bytes.push(((writer.acc >> (8 * b)) & 0xFF) as u8);
     }
 
-    bytes
+    writer.acc = 0;
+    writer.nbits = 0;
+}
+
+/**************************************************************************************************
+                        BitError - Implementations
+ *************************************************************************************************/
+impl Display for BitError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BitError::Io(err) => write!(f, "{}", err),
+            BitError::EndOfStream => write!(f, "end of bitstream"),
+            BitError::InvalidLength(n) => write!(f, "invalid bit length {} (must be 1..=64)", n),
+            BitError::PeekWindowExceeded(n) => write!(f, "peek({}) exceeds the currently buffered window", n),
+            BitError::InvalidCode => write!(f, "no canonical code matched while decoding"),
+            BitError::UnknownSymbol(symbol) => write!(f, "symbol {} has no assigned code", symbol),
+        }
+    }
 }
 
-/// Pad Byte
-fn writer_pad_buf<W: Write>(writer: &mut BitWriter<W>) {
-    let pad_bit = match writer.pad_zero {
-        true => Bit::Zero,
-        false => Bit::One,
-    };
+impl std::error::Error for BitError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            BitError::Io(err) => Some(err),
+            BitError::EndOfStream | BitError::InvalidLength(_) | BitError::PeekWindowExceeded(_) | BitError::InvalidCode | BitError::UnknownSymbol(_) => None,
+        }
+    }
+}
 
-    for _ in 0..(writer.buf.len() % 8) {
-        writer.buf.push_back(pad_bit);
+impl From<std::io::Error> for BitError {
+    fn from(err: std::io::Error) -> Self {
+        BitError::Io(err)
     }
 }
 
@@ -349,3 +743,69 @@ impl From<bool> for Bit {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    #[test]
+    fn read_uint_write_bits_round_trip() {
+        let mut writer = BitWriter::new(Vec::new(), true);
+        writer.write_bits(0b101, 3).unwrap();
+        writer.write_bits(0xAB, 8).unwrap();
+        writer.write_bits(0x1234, 16).unwrap();
+        let bytes = writer.into_inner().unwrap();
+
+        let mut reader = BitReader::new(Cursor::new(bytes)).unwrap();
+        assert_eq!(reader.read_uint(3).unwrap(), 0b101);
+        assert_eq!(reader.read_uint(8).unwrap(), 0xAB);
+        assert_eq!(reader.read_uint(16).unwrap(), 0x1234);
+    }
+
+    #[test]
+    fn bit_order_round_trip() {
+        let orders = [
+            BitOrder::BigEndian,
+            BitOrder::LittleEndian,
+            BitOrder::LittleEndian16,
+            BitOrder::LittleEndian32,
+        ];
+
+        for order in orders {
+            let mut writer = BitWriter::with_order(Vec::new(), true, order);
+            writer.write_bits(0x1A2B, 16).unwrap();
+            writer.write_bits(0x3C, 8).unwrap();
+            let bytes = writer.into_inner().unwrap();
+
+            let mut reader = BitReader::with_order(Cursor::new(bytes), order).unwrap();
+            assert_eq!(reader.read_uint(16).unwrap(), 0x1A2B);
+            assert_eq!(reader.read_uint(8).unwrap(), 0x3C);
+        }
+    }
+
+    #[test]
+    fn seek_bits_repositions_and_refills_buffer() {
+        let data = vec![0xFF, 0x00, 0xAB, 0xCD];
+        let mut reader = BitReader::new(Cursor::new(data)).unwrap();
+
+        reader.seek_bits(SeekFrom::Start(16)).unwrap();
+        assert_eq!(reader.bit_pos(), 16);
+        assert_eq!(reader.read_uint(8).unwrap(), 0xAB);
+    }
+
+    #[test]
+    fn peek_rejects_span_reaching_the_buffered_window_boundary() {
+        let data = vec![0xFFu8, 0x00];
+        let mut reader = BitReader::new(Cursor::new(data)).unwrap();
+
+        // The buffered window holds exactly 16 bits; peeking all of them would
+        // have to consume the last one, which eagerly refills `buf` underneath
+        // the restored position instead of leaving it untouched.
+        assert!(matches!(reader.peek(16), Err(BitError::PeekWindowExceeded(16))));
+
+        // One bit short of the window is safe and must round-trip with read_uint.
+        let peeked = reader.peek(15).unwrap();
+        assert_eq!(reader.read_uint(15).unwrap(), peeked);
+    }
+}